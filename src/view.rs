@@ -1,4 +1,6 @@
-use std::cell::Ref;
+use std::cell::{Cell, Ref, RefCell};
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
 
 use egui::Widget;
 use uuid::Uuid;
@@ -10,23 +12,172 @@ use crate::{
     RSSucks,
 };
 
+/// Identifies which set of feeds an aggregate view should pull entries from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedKind {
+    All,
+    Folder(FolderId),
+    Feed(FeedId),
+}
+
 pub trait Window {
     fn show(&mut self, ctx: &egui::Context);
     fn is_open(&self) -> bool;
 }
 
+/// Live search + filter bar shared by the flow views, following the same
+/// `object_search` + `filter_*` pattern the config UI uses: a query string
+/// compiled into a glob/substring matcher once per change, plus a handful
+/// of independent boolean toggles that narrow the result set further.
+pub struct SearchState {
+    query: String,
+    matcher: Option<glob::Pattern>,
+    unread_only: bool,
+    has_images_only: bool,
+    title_author_only: bool,
+}
+
+impl Default for SearchState {
+    fn default() -> Self {
+        Self {
+            query: String::new(),
+            matcher: None,
+            unread_only: false,
+            has_images_only: false,
+            title_author_only: false,
+        }
+    }
+}
+
+impl SearchState {
+    fn set_query(&mut self, query: String) {
+        if query == self.query {
+            return;
+        }
+        self.matcher = if query.trim().is_empty() {
+            None
+        } else if query.contains(['*', '?']) {
+            glob::Pattern::new(&query).ok()
+        } else {
+            glob::Pattern::new(&format!("*{query}*")).ok()
+        };
+        self.query = query;
+    }
+
+    /// Checks an entry's searchable text against the compiled query, and
+    /// against the `unread_only`/`has_images_only`/`title_author_only`
+    /// toggles. `is_read` is the entry's current read state as tracked by
+    /// `RssClient`.
+    fn matches(
+        &self,
+        title: &str,
+        summary: &str,
+        author: Option<&str>,
+        has_images: bool,
+        is_read: bool,
+    ) -> bool {
+        if self.unread_only && is_read {
+            return false;
+        }
+        if self.has_images_only && !has_images {
+            return false;
+        }
+
+        let Some(pattern) = &self.matcher else {
+            return true;
+        };
+
+        let options = glob::MatchOptions {
+            case_sensitive: false,
+            ..Default::default()
+        };
+        let author = author.unwrap_or("");
+        if self.title_author_only {
+            pattern.matches_with(title, options) || pattern.matches_with(author, options)
+        } else {
+            pattern.matches_with(title, options)
+                || pattern.matches_with(summary, options)
+                || pattern.matches_with(author, options)
+        }
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("🔍");
+            let mut query = self.query.clone();
+            if ui.text_edit_singleline(&mut query).changed() {
+                self.set_query(query);
+            }
+            ui.checkbox(&mut self.unread_only, "仅未读");
+            ui.checkbox(&mut self.has_images_only, "仅含图片");
+            ui.checkbox(&mut self.title_author_only, "仅标题/作者");
+        });
+    }
+}
+
+/// Shared prev/next/jump-to-page bar. Derives the total page count from
+/// `total_items` and `per_page`, clamps `page` into `[1, total_pages]`, and
+/// returns the clamped `(page, per_page)` for the caller to slice entries
+/// with. `page`/`per_page` live behind `Cell`s so views (which only ever
+/// get `&self`) can still let the user paginate and resize pages.
+fn pagination_ui(
+    ui: &mut egui::Ui,
+    page: &Cell<usize>,
+    per_page: &Cell<usize>,
+    total_items: usize,
+) -> (usize, usize) {
+    let mut per_page_value = per_page.get().max(1);
+    let total_pages = ((total_items + per_page_value - 1) / per_page_value).max(1);
+    let mut page_value = page.get().clamp(1, total_pages);
+
+    ui.horizontal(|ui| {
+        if ui
+            .add_enabled(page_value > 1, egui::Button::new("⬅ 上一页"))
+            .clicked()
+        {
+            page_value -= 1;
+        }
+        ui.label(format!("第 {page_value} / {total_pages} 页"));
+        if ui
+            .add_enabled(page_value < total_pages, egui::Button::new("下一页 ➡"))
+            .clicked()
+        {
+            page_value += 1;
+        }
+        ui.label("跳转到：");
+        ui.add(egui::DragValue::new(&mut page_value).range(1..=total_pages));
+        ui.label("每页：");
+        ui.add(egui::DragValue::new(&mut per_page_value).range(1..=100));
+    });
+
+    page_value = page_value.clamp(1, total_pages);
+    page.set(page_value);
+    per_page.set(per_page_value);
+    (page_value, per_page_value)
+}
+
 pub struct FeedFlowView {
     id: FeedId,
-    page: usize,
-    per_page: usize,
+    page: Cell<usize>,
+    per_page: Cell<usize>,
+    search: RefCell<SearchState>,
+    // Entries marked read while shown under "仅未读" stay pinned here so
+    // they don't vanish out of the list the instant they're looked at;
+    // cleared once the filter is switched off, since it's no longer
+    // hiding anything for the pin to protect against.
+    revealed_while_unread: RefCell<HashSet<String>>,
+    was_unread_only: Cell<bool>,
 }
 
 impl<'a> FeedFlowView {
     pub fn new(id: FeedId) -> Self {
         Self {
             id,
-            page: 1,
-            per_page: 5,
+            page: Cell::new(1),
+            per_page: Cell::new(5),
+            search: RefCell::new(SearchState::default()),
+            revealed_while_unread: RefCell::new(HashSet::new()),
+            was_unread_only: Cell::new(false),
         }
     }
 }
@@ -50,15 +201,74 @@ impl View for FeedFlowView {
                 if let Some(description) = model.description {
                     ui.heading(&description.content);
                 };
+
+                ui.horizontal(|ui| {
+                    if let Some(last_synced) = app.rss_client.get_last_synced(self.id) {
+                        ui.label(format!("上次同步 {}", last_synced));
+                    }
+                    let mut interval_secs = app.rss_client.get_refresh_interval(self.id).as_secs();
+                    ui.label("自动刷新间隔（秒）：");
+                    if ui
+                        .add(egui::DragValue::new(&mut interval_secs).range(0..=86400))
+                        .changed()
+                    {
+                        app.rss_client
+                            .set_refresh_interval(self.id, Duration::from_secs(interval_secs));
+                    }
+                    if interval_secs == 0 {
+                        ui.label("（已关闭自动刷新）");
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label(format!(
+                        "{} 篇未读",
+                        app.rss_client.unread_count(self.id)
+                    ));
+                    if ui.button("全部标记为已读").clicked() {
+                        app.rss_client.mark_all_read(self.id);
+                    }
+                });
+
+                ui.separator();
+
+                self.search.borrow_mut().ui(ui);
+                ui.separator();
+
+                let search = self.search.borrow();
+                if self.was_unread_only.get() && !search.unread_only {
+                    self.revealed_while_unread.borrow_mut().clear();
+                }
+                self.was_unread_only.set(search.unread_only);
+
+                let filtered: Vec<_> = model
+                    .entries
+                    .iter()
+                    .filter(|entry| {
+                        let content = entry
+                            .summary
+                            .iter()
+                            .next()
+                            .map(|content| content.content.as_str())
+                            .unwrap_or_default();
+                        let title = entry
+                            .title
+                            .as_ref()
+                            .map(|title| title.content.as_str())
+                            .unwrap_or_default();
+                        let author = entry.authors.iter().next().map(|author| author.name.as_str());
+                        let has_images = content.contains("<img");
+                        let is_read = app.rss_client.is_entry_read(self.id, &entry.id)
+                            && !self.revealed_while_unread.borrow().contains(&entry.id);
+                        search.matches(title, content, author, has_images, is_read)
+                    })
+                    .collect();
+
+                let (page, per_page) = pagination_ui(ui, &self.page, &self.per_page, filtered.len());
                 ui.separator();
 
                 egui::ScrollArea::vertical().show(ui, |ui| {
-                    for entry in model
-                        .entries
-                        .iter()
-                        .skip((self.page - 1) * self.per_page)
-                        .take(self.per_page)
-                    {
+                    for entry in filtered.iter().skip((page - 1) * per_page).take(per_page) {
                         let content = entry
                             .summary
                             .iter()
@@ -97,11 +307,24 @@ impl View for FeedFlowView {
                             content.as_str(),
                         );
                         let ctx = ui.ctx().clone();
-                        component.render_preview_component(&ctx, ui).unwrap();
+                        let response = ui
+                            .scope(|ui| component.render_preview_component(&ctx, ui).unwrap())
+                            .response;
+
+                        // Only count an entry as read once its row has
+                        // actually scrolled into the viewport, not the
+                        // instant it's laid out on the current page. Pin
+                        // it as still-"visible" for the unread filter so
+                        // the act of marking it read doesn't yank it out
+                        // of the list the reader is currently looking at.
+                        if ui.is_rect_visible(response.rect) {
+                            app.rss_client.mark_entry_read(self.id, &entry.id);
+                            self.revealed_while_unread
+                                .borrow_mut()
+                                .insert(entry.id.clone());
+                        }
                     }
                 });
-
-                ui.label("第一页（暂时还没写翻页的操作");
             }
             None => {
                 ui.label("该订阅尚未同步，现在同步吗？");
@@ -113,6 +336,193 @@ impl View for FeedFlowView {
     }
 }
 
+/// Merges entries from every feed matching `kind` into a single
+/// reverse-chronological stream, so folders (or the whole subscription
+/// list) can be read as one timeline instead of feed-by-feed.
+///
+/// Sorting every feed's entries on every frame would be wasteful, so the
+/// merged order is cached and only rebuilt once `interval_ms` has passed
+/// since `last_computed`. `show` takes `&self`, so the cache lives behind
+/// a `RefCell`.
+pub struct AggregateFlowView {
+    kind: FeedKind,
+    page: Cell<usize>,
+    per_page: Cell<usize>,
+    interval_ms: u64,
+    cache: RefCell<Vec<(FeedId, usize)>>,
+    last_computed: RefCell<Option<Instant>>,
+    search: RefCell<SearchState>,
+}
+
+impl AggregateFlowView {
+    pub fn new(kind: FeedKind) -> Self {
+        Self {
+            kind,
+            page: Cell::new(1),
+            per_page: Cell::new(5),
+            interval_ms: 1000,
+            cache: RefCell::new(Vec::new()),
+            last_computed: RefCell::new(None),
+            search: RefCell::new(SearchState::default()),
+        }
+    }
+
+    fn feeds_for_kind(&self, app: &RSSucks) -> Vec<FeedId> {
+        match self.kind {
+            FeedKind::All => app.rss_client.list_all_feed(),
+            FeedKind::Folder(folder_id) => app.rss_client.list_feed_in_folder(folder_id),
+            FeedKind::Feed(feed_id) => vec![feed_id],
+        }
+    }
+
+    /// Rebuilds `cache` if `interval_ms` has elapsed since the last
+    /// computation, or if it has never been computed.
+    fn recompute_if_stale(&self, app: &RSSucks) {
+        let is_stale = match *self.last_computed.borrow() {
+            Some(instant) => instant.elapsed() >= Duration::from_millis(self.interval_ms),
+            None => true,
+        };
+        if !is_stale {
+            return;
+        }
+
+        let mut entries: Vec<(FeedId, usize, Option<chrono::DateTime<chrono::Utc>>)> = Vec::new();
+        for feed_id in self.feeds_for_kind(app) {
+            let Some(feed) = app.rss_client.get_feed(&feed_id) else {
+                continue;
+            };
+            let Some(model) = &feed.model else {
+                continue;
+            };
+            for (index, entry) in model.entries.iter().enumerate() {
+                let updated = entry.updated.iter().next().copied();
+                entries.push((feed_id, index, updated));
+            }
+        }
+        // `Option`'s `Ord` puts `None` last here, so entries with no
+        // timestamp sort to the end.
+        entries.sort_by(|a, b| b.2.cmp(&a.2));
+
+        *self.cache.borrow_mut() = entries
+            .into_iter()
+            .map(|(feed_id, index, _)| (feed_id, index))
+            .collect();
+        *self.last_computed.borrow_mut() = Some(Instant::now());
+    }
+}
+
+impl View for AggregateFlowView {
+    fn show(&self, app: &RSSucks, ui: &mut egui::Ui) {
+        self.recompute_if_stale(app);
+
+        ui.heading(match self.kind {
+            FeedKind::All => "所有文章".to_owned(),
+            FeedKind::Folder(folder_id) => app
+                .rss_client
+                .get_folder_name(folder_id)
+                .unwrap_or("文件夹".to_owned()),
+            FeedKind::Feed(feed_id) => app
+                .rss_client
+                .get_feed(&feed_id)
+                .and_then(|feed| feed.model.and_then(|model| model.title))
+                .map(|title| title.content)
+                .unwrap_or("订阅".to_owned()),
+        });
+
+        self.search.borrow_mut().ui(ui);
+        ui.separator();
+
+        let search = self.search.borrow();
+        let cache = self.cache.borrow();
+        let filtered: Vec<_> = cache
+            .iter()
+            .filter(|(feed_id, index)| {
+                let Some(feed) = app.rss_client.get_feed(feed_id) else {
+                    return false;
+                };
+                let Some(model) = &feed.model else {
+                    return false;
+                };
+                let Some(entry) = model.entries.get(**index) else {
+                    return false;
+                };
+                let content = entry
+                    .summary
+                    .iter()
+                    .next()
+                    .map(|content| content.content.as_str())
+                    .unwrap_or_default();
+                let title = entry
+                    .title
+                    .as_ref()
+                    .map(|title| title.content.as_str())
+                    .unwrap_or_default();
+                let author = entry.authors.iter().next().map(|author| author.name.as_str());
+                let has_images = content.contains("<img");
+                let is_read = app.rss_client.is_entry_read(*feed_id, &entry.id);
+                search.matches(title, content, author, has_images, is_read)
+            })
+            .collect();
+
+        let (page, per_page) = pagination_ui(ui, &self.page, &self.per_page, filtered.len());
+        ui.separator();
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for (feed_id, index) in filtered.iter().skip((page - 1) * per_page).take(per_page) {
+                let Some(feed) = app.rss_client.get_feed(feed_id) else {
+                    continue;
+                };
+                let Some(model) = &feed.model else {
+                    continue;
+                };
+                let Some(entry) = model.entries.get(*index) else {
+                    continue;
+                };
+
+                let content = entry
+                    .summary
+                    .iter()
+                    .next()
+                    .map(|content| content.content.clone())
+                    .unwrap_or("no content".to_owned());
+                let time = entry
+                    .updated
+                    .iter()
+                    .next()
+                    .map(|dt| dt.to_string())
+                    .unwrap_or("no time".to_owned());
+                let link = entry
+                    .links
+                    .iter()
+                    .next()
+                    .map(|link| link.href.as_str())
+                    .unwrap_or("no link");
+                let title = entry
+                    .title
+                    .as_ref()
+                    .map(|title| title.content.clone())
+                    .unwrap_or("unnamed".to_owned());
+                let author = entry
+                    .authors
+                    .iter()
+                    .next()
+                    .map(|author| author.name.as_str());
+                let channel = feed.url.as_str();
+                let component = renderer::ArticleComponent::new(
+                    channel,
+                    author,
+                    title.as_str(),
+                    link,
+                    time.as_str(),
+                    content.as_str(),
+                );
+                let ctx = ui.ctx().clone();
+                component.render_preview_component(&ctx, ui).unwrap();
+            }
+        });
+    }
+}
+
 pub struct InfoWindow {
     id: egui::Id,
     is_open: bool,
@@ -153,6 +563,7 @@ pub struct NewFeedWindow {
     is_open: bool,
     folder_id: Option<FolderId>,
     feed_url: String,
+    refresh_interval_secs: u64,
 }
 
 impl NewFeedWindow {
@@ -163,6 +574,7 @@ impl NewFeedWindow {
             is_open: true,
             folder_id,
             feed_url: String::new(),
+            refresh_interval_secs: 3600,
         }
     }
 }
@@ -180,11 +592,21 @@ impl Window for NewFeedWindow {
                     ui.text_edit_singleline(&mut self.feed_url);
                 });
 
+                ui.horizontal(|ui| {
+                    ui.label("自动刷新间隔（秒，0 为关闭）：");
+                    ui.add(egui::DragValue::new(&mut self.refresh_interval_secs).range(0..=86400));
+                });
+
                 ui.horizontal(|ui| {
                     match url::Url::parse(&self.feed_url) {
                         Ok(url) => {
                             if ui.button("✔").on_hover_text("确定").clicked() {
-                                self.client.create_feed_with_folder(url, self.folder_id);
+                                let feed_id =
+                                    self.client.create_feed_with_folder(url, self.folder_id);
+                                self.client.set_refresh_interval(
+                                    feed_id,
+                                    Duration::from_secs(self.refresh_interval_secs),
+                                );
                                 self.is_open = false;
                             }
                         }
@@ -280,7 +702,27 @@ impl<'app> LeftSidePanel<'app> {
                     .add_window(NewFolderWindow::new(self.app.rss_client.clone()));
             }
 
+            if ui.button("所有文章").clicked() {
+                self.app.tabs.open_or_focus(
+                    TabIdentity::Aggregate(FeedKind::All),
+                    "所有文章".to_owned(),
+                    || Box::new(AggregateFlowView::new(FeedKind::All)),
+                );
+            }
+
             for folder_id in self.app.rss_client.list_folder() {
+                if ui.button("📂 查看整个文件夹").clicked() {
+                    let title = self
+                        .app
+                        .rss_client
+                        .get_folder_name(folder_id)
+                        .unwrap_or("文件夹".to_owned());
+                    self.app.tabs.open_or_focus(
+                        TabIdentity::Aggregate(FeedKind::Folder(folder_id)),
+                        title,
+                        || Box::new(AggregateFlowView::new(FeedKind::Folder(folder_id))),
+                    );
+                }
                 ui.add(CollapsingFolder::new(&self.app, folder_id));
             }
 
@@ -295,6 +737,199 @@ pub trait View {
     fn show(&self, app: &RSSucks, ui: &mut egui::Ui);
 }
 
+/// Identifies an open tab's subject so clicking the same feed/folder twice
+/// focuses the existing tab instead of opening a duplicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabIdentity {
+    Feed(FeedId),
+    Aggregate(FeedKind),
+}
+
+struct Tab {
+    identity: TabIdentity,
+    title: String,
+    view: Box<dyn View>,
+}
+
+/// Owns every open reading tab and which one(s) are currently visible,
+/// modelled on an editor's tabs-and-splits: `LeftSidePanel` clicks call
+/// `open_or_focus`, and `CentralPanel` just renders whatever this holds.
+/// `show` only ever gets `&RSSucks`, so the tab list and indices live
+/// behind `RefCell`/`Cell`.
+#[derive(Default)]
+pub struct TabManager {
+    tabs: RefCell<Vec<Tab>>,
+    active: Cell<usize>,
+    split: Cell<Option<usize>>,
+}
+
+impl TabManager {
+    /// Focuses the tab for `identity` if one is already open, otherwise
+    /// opens a new one built by `make_view` and focuses that.
+    pub fn open_or_focus(
+        &self,
+        identity: TabIdentity,
+        title: String,
+        make_view: impl FnOnce() -> Box<dyn View>,
+    ) {
+        let mut tabs = self.tabs.borrow_mut();
+        if let Some(index) = tabs.iter().position(|tab| tab.identity == identity) {
+            self.active.set(index);
+            return;
+        }
+        tabs.push(Tab {
+            identity,
+            title,
+            view: make_view(),
+        });
+        self.active.set(tabs.len() - 1);
+    }
+
+    pub fn close(&self, index: usize) {
+        let mut tabs = self.tabs.borrow_mut();
+        if index >= tabs.len() {
+            return;
+        }
+        tabs.remove(index);
+
+        let active = self.active.get();
+        if index < active {
+            self.active.set(active - 1);
+        } else if active >= tabs.len() && !tabs.is_empty() {
+            self.active.set(tabs.len() - 1);
+        }
+
+        match self.split.get() {
+            Some(split) if split == index => self.split.set(None),
+            Some(split) if split > index => self.split.set(Some(split - 1)),
+            _ => {}
+        }
+    }
+
+    pub fn reorder(&self, from: usize, to: usize) {
+        let mut tabs = self.tabs.borrow_mut();
+        if from < tabs.len() && to < tabs.len() && from != to {
+            let tab = tabs.remove(from);
+            tabs.insert(to, tab);
+
+            self.active.set(Self::remap_moved_index(self.active.get(), from, to));
+            if let Some(split) = self.split.get() {
+                self.split.set(Some(Self::remap_moved_index(split, from, to)));
+            }
+        }
+    }
+
+    /// Recomputes where `index` ends up after the tab at `from` is moved to
+    /// `to` (as `Vec::remove` + `Vec::insert` does), so `active`/`split`
+    /// keep pointing at the same logical tab rather than whatever now
+    /// occupies their old slot.
+    fn remap_moved_index(index: usize, from: usize, to: usize) -> usize {
+        if index == from {
+            to
+        } else if from < to {
+            if index > from && index <= to {
+                index - 1
+            } else {
+                index
+            }
+        } else if index >= to && index < from {
+            index + 1
+        } else {
+            index
+        }
+    }
+
+    pub fn set_active(&self, index: usize) {
+        if index < self.tabs.borrow().len() {
+            self.active.set(index);
+        }
+    }
+
+    /// Toggles a side-by-side split against `index`; toggling the tab that
+    /// is already split against closes the split.
+    pub fn toggle_split(&self, index: usize) {
+        self.split
+            .set(if self.split.get() == Some(index) {
+                None
+            } else {
+                Some(index)
+            });
+    }
+
+    fn show(&self, app: &RSSucks, ui: &mut egui::Ui) {
+        if self.tabs.borrow().is_empty() {
+            return;
+        }
+
+        // Clicks are collected into locals and only applied once the
+        // `tabs` borrow below is dropped: `close`/`reorder` need
+        // `tabs.borrow_mut()`, which would panic if called while this
+        // loop still held `tabs` borrowed immutably.
+        let mut to_close = None;
+        let mut to_reorder = None;
+
+        ui.horizontal_wrapped(|ui| {
+            let tabs = self.tabs.borrow();
+            let tab_count = tabs.len();
+            for (index, tab) in tabs.iter().enumerate() {
+                ui.group(|ui| {
+                    if ui
+                        .selectable_label(self.active.get() == index, &tab.title)
+                        .clicked()
+                    {
+                        self.set_active(index);
+                    }
+                    if ui
+                        .add_enabled(index > 0, egui::Button::new("⬅"))
+                        .on_hover_text("左移")
+                        .clicked()
+                    {
+                        to_reorder = Some((index, index - 1));
+                    }
+                    if ui
+                        .add_enabled(index + 1 < tab_count, egui::Button::new("➡"))
+                        .on_hover_text("右移")
+                        .clicked()
+                    {
+                        to_reorder = Some((index, index + 1));
+                    }
+                    if ui.small_button("⬌").on_hover_text("左右分屏").clicked() {
+                        self.toggle_split(index);
+                    }
+                    if ui.small_button("🗙").on_hover_text("关闭").clicked() {
+                        to_close = Some(index);
+                    }
+                });
+            }
+        });
+        ui.separator();
+
+        if let Some((from, to)) = to_reorder {
+            self.reorder(from, to);
+        }
+        if let Some(index) = to_close {
+            self.close(index);
+        }
+
+        let tabs = self.tabs.borrow();
+        if tabs.is_empty() {
+            return;
+        }
+        let active = self.active.get();
+        match self.split.get() {
+            Some(split_index) if split_index != active && split_index < tabs.len() => {
+                ui.columns(2, |columns| {
+                    tabs[active].view.show(app, &mut columns[0]);
+                    tabs[split_index].view.show(app, &mut columns[1]);
+                });
+            }
+            _ => {
+                tabs[active].view.show(app, ui);
+            }
+        }
+    }
+}
+
 pub struct CentralPanel<'app> {
     app: &'app RSSucks,
 }
@@ -307,11 +942,13 @@ impl<'app> CentralPanel<'app> {
 
 impl<'app> CentralPanel<'app> {
     pub fn show(&mut self, ctx: &egui::Context) {
-        egui::CentralPanel::default().show(ctx, |ui| match &self.app.view {
-            Some(view) => {
-                view.show(self.app, ui);
-            }
-            None => {}
+        // Kick off background refreshes for any feed whose interval has
+        // elapsed. `try_start_sync_feed`'s own `feed_is_syncing` guard
+        // keeps this from double-fetching a feed that's already in flight.
+        self.app.rss_client.poll_due_syncs();
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            self.app.tabs.show(self.app, ui);
         });
     }
 }
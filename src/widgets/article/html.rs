@@ -0,0 +1,132 @@
+use egui::ImageSource;
+use scraper::{Html, Node};
+
+use super::{Element, TextStyle};
+
+/// Walks a parsed HTML document depth-first, turning text nodes into
+/// styled `Element::Text`s and `<img>` tags into `Element::Image`s, in
+/// the order they appear. This is deliberately a flat walk rather than a
+/// full markdown AST: `Preview` only needs an ordered run list to render,
+/// not a tree it would have to flatten again.
+///
+/// `pending_break` is threaded through the whole walk: block elements
+/// (headings, paragraphs, list items, blockquotes, `<br>`, images) set it
+/// so the *next* run emitted is flagged `starts_block`, while inline
+/// elements (`<b>`, `<i>`, `<a>`, ...) leave it untouched so their text
+/// keeps flowing onto the same line as their surrounding paragraph.
+pub fn parse(fragment: &str) -> Vec<Element> {
+    let document = Html::parse_fragment(fragment);
+    let mut elements = Vec::new();
+    let mut pending_break = true;
+    walk(
+        document.tree.root(),
+        &TextStyle::default(),
+        &mut pending_break,
+        &mut elements,
+    );
+    elements
+}
+
+fn walk(
+    node: ego_tree::NodeRef<'_, Node>,
+    style: &TextStyle,
+    pending_break: &mut bool,
+    elements: &mut Vec<Element>,
+) {
+    for child in node.children() {
+        match child.value() {
+            Node::Text(text) => {
+                let content = normalize_whitespace(text);
+                if content.is_empty() {
+                    continue;
+                }
+                // A run that collapsed to a single space is only
+                // significant between two inline runs on the same line
+                // (the gap in `<a>a</a> <a>b</a>`); at a block boundary
+                // it's just source indentation/newlines and carries no
+                // meaning, so it's dropped rather than rendered as a
+                // spurious leading space.
+                if content == " " && (*pending_break || elements.is_empty()) {
+                    continue;
+                }
+                elements.push(Element::text(content, style.clone(), *pending_break));
+                *pending_break = false;
+            }
+            Node::Element(el) => {
+                let mut child_style = style.clone();
+                let is_block = matches!(
+                    el.name(),
+                    "h1" | "h2"
+                        | "h3"
+                        | "h4"
+                        | "h5"
+                        | "h6"
+                        | "p"
+                        | "div"
+                        | "li"
+                        | "blockquote"
+                );
+                match el.name() {
+                    "h1" => child_style.heading_level = Some(1),
+                    "h2" => child_style.heading_level = Some(2),
+                    "h3" => child_style.heading_level = Some(3),
+                    "h4" | "h5" | "h6" => child_style.heading_level = Some(4),
+                    "b" | "strong" => child_style.bold = true,
+                    "i" | "em" => child_style.italic = true,
+                    "code" | "pre" => child_style.code = true,
+                    "blockquote" => child_style.blockquote = true,
+                    "li" => child_style.list_item = true,
+                    "a" => {
+                        child_style.link = el.attr("href").map(|href| href.to_owned());
+                    }
+                    "br" => {
+                        *pending_break = true;
+                        continue;
+                    }
+                    "img" => {
+                        if let Some(src) = el.attr("src") {
+                            elements.push(Element::image(ImageSource::Uri(
+                                src.to_owned().into(),
+                            )));
+                            *pending_break = true;
+                        }
+                        continue;
+                    }
+                    "script" | "style" => continue,
+                    _ => {}
+                }
+                if is_block {
+                    *pending_break = true;
+                }
+                walk(child, &child_style, pending_break, elements);
+                if is_block {
+                    *pending_break = true;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Collapses runs of whitespace (spaces, tabs, newlines from source
+/// indentation) into a single space, the way a browser treats HTML
+/// whitespace, instead of trimming every text node to nothing at its
+/// edges. This keeps a real, single space where the source had one
+/// (`Hello <a>world</a>` keeps the gap before "world") and adds none
+/// where it didn't (`<a>world</a>!` stays glued to the "!").
+fn normalize_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last_was_space = false;
+    for ch in text.chars() {
+        if ch.is_whitespace() {
+            if !last_was_space {
+                out.push(' ');
+            }
+            last_was_space = true;
+        } else {
+            out.push(ch);
+            last_was_space = false;
+        }
+    }
+    out
+}
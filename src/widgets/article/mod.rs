@@ -0,0 +1,114 @@
+mod html;
+mod preview;
+
+pub use preview::Preview;
+
+use egui::ImageSource;
+
+/// Style flags carried by a run of text produced while walking article
+/// HTML. Flags are additive (e.g. a bold link inside a blockquote sets
+/// `bold`, `link` and `blockquote` together) rather than modelled as a
+/// tree, since `Preview` only ever needs to render a flat, ordered list
+/// of runs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextStyle {
+    pub heading_level: Option<u8>,
+    pub bold: bool,
+    pub italic: bool,
+    pub code: bool,
+    pub blockquote: bool,
+    pub list_item: bool,
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElementType {
+    Text,
+    Image,
+}
+
+/// One piece of parsed article content, kept in source order so images
+/// can be rendered inline instead of being collected into a trailing
+/// strip. `starts_block` marks runs that begin a new heading/paragraph/
+/// list-item/blockquote/image, so `Preview` knows where it may keep
+/// flowing inline text onto the same line versus where it must break.
+pub struct Element {
+    pub typ: ElementType,
+    pub text_tuple: (Option<String>, TextStyle),
+    pub image_tuple: (Option<ImageSource<'static>>,),
+    pub starts_block: bool,
+}
+
+impl Element {
+    pub fn text(content: impl Into<String>, style: TextStyle, starts_block: bool) -> Self {
+        Self {
+            typ: ElementType::Text,
+            text_tuple: (Some(content.into()), style),
+            image_tuple: (None,),
+            starts_block,
+        }
+    }
+
+    pub fn image(src: ImageSource<'static>) -> Self {
+        Self {
+            typ: ElementType::Image,
+            text_tuple: (None, TextStyle::default()),
+            image_tuple: (Some(src),),
+            starts_block: true,
+        }
+    }
+}
+
+pub struct Builder<'a> {
+    elements: Option<Vec<Element>>,
+    max_rows: usize,
+    break_anywhere: bool,
+    overflow_character: Option<char>,
+    fulltext: Option<String>,
+    title: &'a str,
+}
+
+impl<'a> Builder<'a> {
+    pub fn new(title: &'a str) -> Self {
+        Self {
+            elements: None,
+            max_rows: usize::MAX,
+            break_anywhere: false,
+            overflow_character: None,
+            fulltext: None,
+            title,
+        }
+    }
+
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    pub fn break_anywhere(mut self, break_anywhere: bool) -> Self {
+        self.break_anywhere = break_anywhere;
+        self
+    }
+
+    pub fn overflow_character(mut self, overflow_character: char) -> Self {
+        self.overflow_character = Some(overflow_character);
+        self
+    }
+
+    pub fn fulltext(mut self, fulltext: impl Into<String>) -> Self {
+        self.fulltext = Some(fulltext.into());
+        self
+    }
+
+    /// Parses `html` into the crate's own `Element` list so `Preview` can
+    /// render headings, emphasis, links, lists and inline images instead
+    /// of dumping the markup as flat text.
+    pub fn html(mut self, html: &str) -> Self {
+        self.elements = Some(html::parse(html));
+        self
+    }
+
+    pub fn preview(&self) -> Preview {
+        Preview::from(self)
+    }
+}
@@ -1,7 +1,9 @@
-use egui::{Image, Margin, RichText, Rounding, Widget};
+use std::ops::Range;
+
+use egui::{Image, Margin, RichText, Rounding, TextFormat, Widget};
 use uuid::Uuid;
 
-use super::{Builder, Element, ElementType};
+use super::{Builder, Element, ElementType, TextStyle};
 
 pub struct Preview<'a> {
     // rendering previews needs ownership
@@ -15,6 +17,89 @@ pub struct Preview<'a> {
     title: String,
 }
 
+/// Maps a parsed run's style flags onto an `egui::TextFormat`, so headings,
+/// bold/italic, inline code and links read as something other than flat
+/// body text. `ui` supplies the theme's "strong" color, the same one
+/// `RichText::strong` uses, since egui has no dedicated bold font weight.
+fn text_format(ui: &egui::Ui, style: &TextStyle) -> TextFormat {
+    let mut format = TextFormat::default();
+    if let Some(level) = style.heading_level {
+        format.font_id.size = match level {
+            1 => 28.0,
+            2 => 24.0,
+            3 => 20.0,
+            _ => 18.0,
+        };
+    }
+    format.italics = style.italic;
+    if style.bold {
+        format.color = ui.visuals().strong_text_color();
+    }
+    if style.code {
+        format.font_id.family = egui::FontFamily::Monospace;
+    }
+    if style.blockquote {
+        format.background = ui.visuals().code_bg_color;
+    }
+    if style.link.is_some() {
+        format.color = ui.visuals().hyperlink_color;
+        format.underline = egui::Stroke::new(1.0, format.color);
+    }
+    format
+}
+
+/// Renders a finished `LayoutJob`, making it an actual hyperlink when
+/// `links` (byte ranges into the job's text paired with their `href`)
+/// isn't empty: clicking anywhere in the label opens the href under the
+/// pointer (or the run's only link, if the whole run is one), instead of
+/// the underline just being decorative.
+fn show_job(ui: &mut egui::Ui, job: egui::text::LayoutJob, links: &[(Range<usize>, String)]) {
+    if links.is_empty() {
+        ui.label(job);
+        return;
+    }
+
+    let galley = ui.fonts(|fonts| fonts.layout_job(job));
+    let response = ui.add(egui::Label::new(galley.clone()).sense(egui::Sense::click()));
+
+    let href_under_pointer = response.hover_pos().and_then(|pos| {
+        let cursor = galley.cursor_from_pos(pos - response.rect.min);
+        links
+            .iter()
+            .find(|(range, _)| range.contains(&cursor.ccursor.index))
+            .map(|(_, href)| href.clone())
+    });
+
+    if href_under_pointer.is_some() {
+        response.clone().on_hover_cursor(egui::CursorIcon::PointingHand);
+    }
+
+    if response.clicked() {
+        let whole_run_link = match links {
+            [(range, href)] if range.start == 0 && range.end == galley.text().len() => {
+                Some(href.clone())
+            }
+            _ => None,
+        };
+        if let Some(href) = href_under_pointer.or(whole_run_link) {
+            ui.ctx().open_url(egui::OpenUrl::new(href));
+        }
+    }
+}
+
+/// Marker prepended to the first run of a block, so list items and
+/// blockquotes read as something other than plain paragraphs even though
+/// `TextFormat` has no notion of indentation.
+fn block_prefix(style: &TextStyle) -> &'static str {
+    if style.list_item {
+        "• "
+    } else if style.blockquote {
+        "▎ "
+    } else {
+        ""
+    }
+}
+
 impl<'a> From<&Builder<'a>> for Preview<'a> {
     fn from(value: &Builder<'a>) -> Self {
         Preview {
@@ -52,52 +137,107 @@ impl<'a> Widget for &Preview<'a> {
                     // Render title:
                     ui.label(RichText::new(self.title).size(20.0).strong());
 
-                    // Render content:
-                    // First, render text.
-                    let mut job = egui::text::LayoutJob::single_section(
-                        self.fulltext.map_or("No content...".to_owned(), |text| text),
-                        egui::TextFormat::default(),
-                    );
-                    job.wrap = egui::text::TextWrapping {
-                        max_rows: self.max_rows,
-                        break_anywhere: self.break_anywhere,
-                        overflow_character: self.overflow_character,
-                        ..Default::default()
-                    };
-                    ui.label(job);
+                    // Render content. When the HTML parsing pass produced a
+                    // structured element list, walk it in order so headings,
+                    // emphasis, links and images interleave the way they did
+                    // in the source article. Otherwise fall back to dumping
+                    // `fulltext` as a single plain section.
+                    match self.elements {
+                        Some(elements) if !elements.is_empty() => {
+                            let mut images_rendered = 0;
+                            // Consecutive inline runs (plain text next to
+                            // `<b>`/`<i>`/`<a>` text) share one `LayoutJob`
+                            // so they flow on the same line; only a run
+                            // marked `starts_block` (headings, new
+                            // paragraphs/list items/blockquotes, images)
+                            // flushes it and starts a fresh one.
+                            let mut current_job: Option<egui::text::LayoutJob> = None;
+                            let mut current_links: Vec<(Range<usize>, String)> = Vec::new();
+                            for element in elements {
+                                match element.typ {
+                                    ElementType::Text => {
+                                        let Some(content) = &element.text_tuple.0 else {
+                                            continue;
+                                        };
+                                        let style = &element.text_tuple.1;
 
-                    // Then render images.
-                    if let Some(elements) = self.elements {
-                        let mut images_iter = elements
-                            .iter()
-                            .filter_map(|element| {
-                                if element.typ == ElementType::Image {
-                                    element.image_tuple.0.as_ref()
-                                } else {
-                                    None
-                                }
-                            })
-                            .take(self.max_images_num)
-                            .peekable();
-                        if images_iter.peek().is_some() {
-                            egui::ScrollArea::horizontal()
-                                .id_source(self.scroll_area_id)
-                                .auto_shrink([false, true])
-                                .drag_to_scroll(true)
-                                .show(ui, |ui| {
-                                    ui.horizontal(|ui| {
-                                        images_iter.for_each(|src| {
+                                        if element.starts_block || current_job.is_none() {
+                                            if let Some(job) = current_job.take() {
+                                                show_job(ui, job, &current_links);
+                                                current_links.clear();
+                                            }
+                                            let mut job = egui::text::LayoutJob::default();
+                                            job.wrap = egui::text::TextWrapping {
+                                                max_rows: self.max_rows,
+                                                break_anywhere: self.break_anywhere,
+                                                overflow_character: self.overflow_character,
+                                                ..Default::default()
+                                            };
+                                            current_job = Some(job);
+                                        }
+
+                                        let job = current_job.as_mut().unwrap();
+                                        let is_first_in_job = job.sections.is_empty();
+                                        // No artificial gap between runs:
+                                        // `html::parse` already normalizes
+                                        // inter-element whitespace into the
+                                        // run text itself, so two runs that
+                                        // were adjacent in the source (e.g.
+                                        // `world!`) stay adjacent here too.
+                                        let prefix = if is_first_in_job {
+                                            block_prefix(style)
+                                        } else {
+                                            ""
+                                        };
+                                        let text = format!("{prefix}{content}");
+                                        let start = job.text.len();
+                                        job.append(&text, 0.0, text_format(ui, style));
+                                        if let Some(href) = &style.link {
+                                            current_links.push((start..job.text.len(), href.clone()));
+                                        }
+                                    }
+                                    ElementType::Image => {
+                                        if let Some(job) = current_job.take() {
+                                            show_job(ui, job, &current_links);
+                                            current_links.clear();
+                                        }
+                                        if images_rendered >= self.max_images_num {
+                                            continue;
+                                        }
+                                        if let Some(src) = &element.image_tuple.0 {
                                             ui.add(
-                                                Image::from(src)
+                                                Image::from(src.clone())
                                                     .fit_to_exact_size(egui::Vec2::new(
-                                                        f32::INFINITY, 128.0,
+                                                        f32::INFINITY,
+                                                        128.0,
                                                     ))
                                                     .rounding(Rounding::ZERO.at_least(10.0))
                                                     .show_loading_spinner(true),
                                             );
-                                        });
-                                    });
-                                });
+                                            images_rendered += 1;
+                                        }
+                                    }
+                                }
+                            }
+                            if let Some(job) = current_job.take() {
+                                show_job(ui, job, &current_links);
+                            }
+                        }
+                        _ => {
+                            let mut job = egui::text::LayoutJob::single_section(
+                                self.fulltext
+                                    .as_deref()
+                                    .unwrap_or("No content...")
+                                    .to_owned(),
+                                egui::TextFormat::default(),
+                            );
+                            job.wrap = egui::text::TextWrapping {
+                                max_rows: self.max_rows,
+                                break_anywhere: self.break_anywhere,
+                                overflow_character: self.overflow_character,
+                                ..Default::default()
+                            };
+                            ui.label(job);
                         }
                     }
                     ui.allocate_space(egui::Vec2 {